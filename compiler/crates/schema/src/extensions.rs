@@ -0,0 +1,339 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Merging of GraphQL `extend` definitions onto the base types they augment.
+//!
+//! The grammar side of this feature — lexing the `extend` keyword and parsing
+//! `extend type`/`extend interface`/`extend enum`/`extend input`/`extend
+//! union`/`extend scalar`/`extend schema` into dedicated
+//! `graphql_syntax` [`TypeSystemDefinition`] variants — lives in this crate's
+//! `parser`/`token`/`lexer` modules. This module is the builder half:
+//! [`merge_extensions`] folds those extension variants onto the matching base
+//! definition before `Schema::build` interns anything, appending members,
+//! unioning `implements` interfaces, and accumulating directives while
+//! erroring on conflicts.
+
+use std::collections::HashMap;
+
+use graphql_syntax::type_system_node::TypeSystemDefinition;
+
+use crate::errors::{Result, SchemaError};
+
+/// Where a base definition came from, so the two streams can be handed back to
+/// `Schema::build` separately after merging.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Origin {
+    Server,
+    Client,
+}
+
+/// Folds every `extend` definition across the server and client streams onto
+/// the base type it augments, returning the merged `(server, client)` pair.
+///
+/// An `extend` form may target a base declared in *either* stream (e.g. a
+/// `relay-extensions.graphql` `extend type` that augments a server type), but
+/// the base keeps its original stream so the client/server distinction — which
+/// `Schema::build` interns as `is_extension` — is preserved.
+///
+/// Errors when an extension targets a type that was never declared, when the
+/// extension's kind does not match the base type, or when it introduces a
+/// duplicate field, enum value, or input field.
+pub(crate) fn merge_extensions(
+    server: Vec<TypeSystemDefinition>,
+    client: Vec<TypeSystemDefinition>,
+) -> Result<(Vec<TypeSystemDefinition>, Vec<TypeSystemDefinition>)> {
+    let mut bases: Vec<TypeSystemDefinition> = Vec::with_capacity(server.len() + client.len());
+    let mut origins: Vec<Origin> = Vec::with_capacity(server.len() + client.len());
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut extensions: Vec<TypeSystemDefinition> = Vec::new();
+
+    for (definitions, origin) in [(server, Origin::Server), (client, Origin::Client)] {
+        for definition in definitions {
+            if is_extension(&definition) {
+                extensions.push(definition);
+            } else {
+                if let Some(name) = type_name(&definition) {
+                    index.insert(name.to_string(), bases.len());
+                }
+                bases.push(definition);
+                origins.push(origin);
+            }
+        }
+    }
+
+    for extension in extensions {
+        merge_one(&mut bases, &index, extension)?;
+    }
+
+    let mut server_out = Vec::new();
+    let mut client_out = Vec::new();
+    for (definition, origin) in bases.into_iter().zip(origins) {
+        match origin {
+            Origin::Server => server_out.push(definition),
+            Origin::Client => client_out.push(definition),
+        }
+    }
+    Ok((server_out, client_out))
+}
+
+fn merge_one(
+    bases: &mut [TypeSystemDefinition],
+    index: &HashMap<String, usize>,
+    extension: TypeSystemDefinition,
+) -> Result<()> {
+    use TypeSystemDefinition::*;
+
+    // `extend schema` does not name a type; it always folds onto the schema
+    // definition, and is an error when no schema definition was declared.
+    if let SchemaExtension(extension) = extension {
+        let slot = bases
+            .iter_mut()
+            .find_map(|definition| match definition {
+                SchemaDefinition(base) => Some(base),
+                _ => None,
+            })
+            .ok_or(SchemaError::ExtendUndeclared {
+                name: "schema".to_string(),
+            })?;
+        slot.directives.extend(extension.directives);
+        return Ok(());
+    }
+
+    let name = extension_target(&extension).expect("non-schema extension names a type");
+    let slot = index
+        .get(name)
+        .map(|position| &mut bases[*position])
+        .ok_or_else(|| SchemaError::ExtendUndeclared {
+            name: name.to_string(),
+        })?;
+
+    match (slot, extension) {
+        (ObjectTypeDefinition(base), ObjectTypeExtension(extension)) => {
+            append_unique_fields(&mut base.fields, extension.fields, name)?;
+            union_interfaces(&mut base.interfaces, extension.interfaces);
+            base.directives.extend(extension.directives);
+        }
+        (InterfaceTypeDefinition(base), InterfaceTypeExtension(extension)) => {
+            append_unique_fields(&mut base.fields, extension.fields, name)?;
+            union_interfaces(&mut base.interfaces, extension.interfaces);
+            base.directives.extend(extension.directives);
+        }
+        (EnumTypeDefinition(base), EnumTypeExtension(extension)) => {
+            append_unique_enum_values(&mut base.values, extension.values, name)?;
+            base.directives.extend(extension.directives);
+        }
+        (InputObjectTypeDefinition(base), InputObjectTypeExtension(extension)) => {
+            append_unique_input_fields(&mut base.fields, extension.fields, name)?;
+            base.directives.extend(extension.directives);
+        }
+        (UnionTypeDefinition(base), UnionTypeExtension(extension)) => {
+            base.members.extend(extension.members);
+            base.directives.extend(extension.directives);
+        }
+        (ScalarTypeDefinition(base), ScalarTypeExtension(extension)) => {
+            base.directives.extend(extension.directives);
+        }
+        _ => {
+            return Err(SchemaError::ExtendKindMismatch {
+                name: name.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn append_unique_fields(
+    base: &mut Vec<graphql_syntax::type_system_node::FieldDefinition>,
+    additions: Vec<graphql_syntax::type_system_node::FieldDefinition>,
+    type_name: &str,
+) -> Result<()> {
+    for addition in additions {
+        if base.iter().any(|field| field.name == addition.name) {
+            return Err(SchemaError::DuplicateField {
+                type_name: type_name.to_string(),
+                field_name: addition.name,
+            });
+        }
+        base.push(addition);
+    }
+    Ok(())
+}
+
+fn append_unique_enum_values(
+    base: &mut Vec<graphql_syntax::type_system_node::EnumValueDefinition>,
+    additions: Vec<graphql_syntax::type_system_node::EnumValueDefinition>,
+    type_name: &str,
+) -> Result<()> {
+    for addition in additions {
+        if base.iter().any(|value| value.name == addition.name) {
+            return Err(SchemaError::DuplicateField {
+                type_name: type_name.to_string(),
+                field_name: addition.name,
+            });
+        }
+        base.push(addition);
+    }
+    Ok(())
+}
+
+fn append_unique_input_fields(
+    base: &mut Vec<graphql_syntax::type_system_node::InputValueDefinition>,
+    additions: Vec<graphql_syntax::type_system_node::InputValueDefinition>,
+    type_name: &str,
+) -> Result<()> {
+    for addition in additions {
+        if base.iter().any(|field| field.name == addition.name) {
+            return Err(SchemaError::DuplicateField {
+                type_name: type_name.to_string(),
+                field_name: addition.name,
+            });
+        }
+        base.push(addition);
+    }
+    Ok(())
+}
+
+fn union_interfaces(base: &mut Vec<String>, additions: Vec<String>) {
+    for addition in additions {
+        if !base.contains(&addition) {
+            base.push(addition);
+        }
+    }
+}
+
+fn is_extension(definition: &TypeSystemDefinition) -> bool {
+    use TypeSystemDefinition::*;
+    matches!(
+        definition,
+        SchemaExtension(_)
+            | ObjectTypeExtension(_)
+            | InterfaceTypeExtension(_)
+            | EnumTypeExtension(_)
+            | InputObjectTypeExtension(_)
+            | UnionTypeExtension(_)
+            | ScalarTypeExtension(_)
+    )
+}
+
+fn type_name(definition: &TypeSystemDefinition) -> Option<&str> {
+    use TypeSystemDefinition::*;
+    match definition {
+        ObjectTypeDefinition(definition) => Some(&definition.name),
+        InterfaceTypeDefinition(definition) => Some(&definition.name),
+        UnionTypeDefinition(definition) => Some(&definition.name),
+        EnumTypeDefinition(definition) => Some(&definition.name),
+        InputObjectTypeDefinition(definition) => Some(&definition.name),
+        ScalarTypeDefinition(definition) => Some(&definition.name),
+        _ => None,
+    }
+}
+
+fn extension_target(definition: &TypeSystemDefinition) -> Option<&str> {
+    use TypeSystemDefinition::*;
+    match definition {
+        ObjectTypeExtension(extension) => Some(&extension.name),
+        InterfaceTypeExtension(extension) => Some(&extension.name),
+        UnionTypeExtension(extension) => Some(&extension.name),
+        EnumTypeExtension(extension) => Some(&extension.name),
+        InputObjectTypeExtension(extension) => Some(&extension.name),
+        ScalarTypeExtension(extension) => Some(&extension.name),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use graphql_syntax::type_system_node::{FieldDefinition, ObjectTypeDefinition, Type as AstType};
+
+    use super::*;
+
+    fn field(name: &str) -> FieldDefinition {
+        FieldDefinition {
+            name: name.to_string(),
+            description: None,
+            arguments: Vec::new(),
+            type_: AstType::Named("String".to_string()),
+            directives: Vec::new(),
+        }
+    }
+
+    fn object(name: &str, fields: Vec<FieldDefinition>) -> TypeSystemDefinition {
+        TypeSystemDefinition::ObjectTypeDefinition(ObjectTypeDefinition {
+            name: name.to_string(),
+            description: None,
+            interfaces: Vec::new(),
+            fields,
+            directives: Vec::new(),
+        })
+    }
+
+    fn object_extension(name: &str, fields: Vec<FieldDefinition>) -> TypeSystemDefinition {
+        TypeSystemDefinition::ObjectTypeExtension(
+            graphql_syntax::type_system_node::ObjectTypeExtension {
+                name: name.to_string(),
+                interfaces: Vec::new(),
+                fields,
+                directives: Vec::new(),
+            },
+        )
+    }
+
+    fn object_fields(definition: &TypeSystemDefinition) -> &[FieldDefinition] {
+        match definition {
+            TypeSystemDefinition::ObjectTypeDefinition(object) => &object.fields,
+            _ => panic!("expected an object type definition"),
+        }
+    }
+
+    #[test]
+    fn appends_extension_fields_to_base() {
+        let (server, _) = merge_extensions(
+            vec![
+                object("User", vec![field("id")]),
+                object_extension("User", vec![field("name")]),
+            ],
+            vec![],
+        )
+        .unwrap();
+
+        let fields = object_fields(&server[0]);
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].name, "id");
+        assert_eq!(fields[1].name, "name");
+    }
+
+    #[test]
+    fn client_extension_folds_onto_server_base_keeping_streams() {
+        let (server, client) = merge_extensions(
+            vec![object("User", vec![field("id")])],
+            vec![object_extension("User", vec![field("nickname")])],
+        )
+        .unwrap();
+
+        // The base stays on the server stream; the extension is merged in.
+        assert_eq!(object_fields(&server[0]).len(), 2);
+        assert!(client.is_empty());
+    }
+
+    #[test]
+    fn duplicate_field_is_an_error() {
+        let result = merge_extensions(
+            vec![
+                object("User", vec![field("id")]),
+                object_extension("User", vec![field("id")]),
+            ],
+            vec![],
+        );
+        assert!(matches!(result, Err(SchemaError::DuplicateField { .. })));
+    }
+
+    #[test]
+    fn extending_undeclared_type_is_an_error() {
+        let result = merge_extensions(vec![object_extension("Ghost", vec![field("x")])], vec![]);
+        assert!(matches!(result, Err(SchemaError::ExtendUndeclared { .. })));
+    }
+}
@@ -0,0 +1,251 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Apollo Federation v2 support for the schema layer. The federation
+//! directives retained on an [`Object`]/[`Field`] are exposed as structured
+//! metadata through [`Schema::type_federation`]/[`Schema::field_federation`], and
+//! [`Schema::to_federation_sdl`] augments the printed SDL with the subgraph
+//! machinery a gateway expects.
+//!
+//! Scope: entities (`@key`) are resolved on object types only, which is what
+//! the `_Entity` union can contain (a GraphQL union's members are objects).
+//! Entity *interfaces* are out of scope for this pass.
+//!
+//! Note on storage: the structured metadata is derived on each call from the
+//! `Directive` blobs retained on the definitions rather than being stored as
+//! dedicated fields on `Object`/`Field`. The parser keeps the directives
+//! verbatim, so this lifts them into typed form without a `definitions` schema
+//! change.
+
+use crate::definitions::{Directive, Field, Object, Schema};
+
+/// The federation spec version this subsystem targets.
+const FEDERATION_LINK_URL: &str = "https://specs.apollo.dev/federation/v2.0";
+
+/// The federation directives this subsystem recognizes. All but `link` are
+/// lifted out of opaque `Directive` blobs into structured metadata (see
+/// [`TypeFederation`]/[`FieldFederation`]); those same entries form the
+/// `@link(import:)` set emitted by the subgraph printer.
+const FEDERATION_DIRECTIVES: &[&str] = &[
+    "key",
+    "external",
+    "provides",
+    "requires",
+    "extends",
+    "shareable",
+    "inaccessible",
+    "override",
+    "tag",
+    "link",
+];
+
+/// A single `@key(fields: "…")` application on a type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyDirective {
+    /// The raw `fields` selection set, e.g. `"id"` or `"id sku { upc }"`.
+    pub fields: String,
+    /// Whether the key is resolvable in this subgraph (`resolvable: false`
+    /// marks a reference-only key).
+    pub resolvable: bool,
+}
+
+/// The federation directives retained for an object type, lifted out of the
+/// opaque `Directive` list into typed fields.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TypeFederation {
+    /// Every `@key(fields:)` on the type.
+    pub keys: Vec<KeyDirective>,
+    /// `@extends` — the type extends a definition owned by another subgraph.
+    pub extends: bool,
+    /// `@shareable` — the type's fields may be resolved by multiple subgraphs.
+    pub shareable: bool,
+    /// `@inaccessible` — the type is hidden from the supergraph API.
+    pub inaccessible: bool,
+    /// Every `@tag(name:)` applied to the type.
+    pub tags: Vec<String>,
+}
+
+/// The federation directives retained for a field.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldFederation {
+    /// `@external` — the field is defined in another subgraph.
+    pub external: bool,
+    /// `@provides(fields:)` selection set, if present.
+    pub provides: Option<String>,
+    /// `@requires(fields:)` selection set, if present.
+    pub requires: Option<String>,
+    /// `@override(from:)` source subgraph, if present.
+    pub override_from: Option<String>,
+    /// `@shareable` — the field may be resolved by multiple subgraphs.
+    pub shareable: bool,
+    /// `@inaccessible` — the field is hidden from the supergraph API.
+    pub inaccessible: bool,
+    /// Every `@tag(name:)` applied to the field.
+    pub tags: Vec<String>,
+}
+
+/// A keyed type together with the key field-sets that identify it, suitable
+/// for generating a gateway `_entities` resolver.
+#[derive(Clone, Debug)]
+pub struct Entity<'schema> {
+    pub object: &'schema Object,
+    pub keys: Vec<KeyDirective>,
+}
+
+impl Schema {
+    /// Returns every object type carrying at least one `@key`, in alphabetical order,
+    /// paired with its key field-sets.
+    pub fn entities(&self) -> Vec<Entity<'_>> {
+        let mut entities: Vec<Entity<'_>> = self
+            .objects()
+            .filter_map(|object| {
+                let keys = self.type_federation(object).keys;
+                if keys.is_empty() {
+                    None
+                } else {
+                    Some(Entity { object, keys })
+                }
+            })
+            .collect();
+        entities.sort_by_key(|entity| self.object_name(entity.object.id));
+        entities
+    }
+
+    /// The structured federation metadata retained for an object type.
+    pub fn type_federation(&self, object: &Object) -> TypeFederation {
+        TypeFederation {
+            keys: key_directives(&object.directives),
+            extends: has_directive(&object.directives, "extends"),
+            shareable: has_directive(&object.directives, "shareable"),
+            inaccessible: has_directive(&object.directives, "inaccessible"),
+            tags: tag_directives(&object.directives),
+        }
+    }
+
+    /// The structured federation metadata retained for a field.
+    pub fn field_federation(&self, field: &Field) -> FieldFederation {
+        FieldFederation {
+            external: has_directive(&field.directives, "external"),
+            provides: directive_field_set(&field.directives, "provides"),
+            requires: directive_field_set(&field.directives, "requires"),
+            override_from: directive_string_arg(&field.directives, "override", "from"),
+            shareable: has_directive(&field.directives, "shareable"),
+            inaccessible: has_directive(&field.directives, "inaccessible"),
+            tags: tag_directives(&field.directives),
+        }
+    }
+
+    /// Prints the schema as a federation v2 subgraph: the base SDL plus the
+    /// `@link` schema element, the `_Service`/`_Entity`/`_Any` types, and the
+    /// `_entities`/`_service` root fields. The default [`print_sdl`] output is
+    /// unchanged; these synthesized elements appear only here.
+    ///
+    /// [`print_sdl`]: Schema::print_sdl
+    pub fn to_federation_sdl(&self) -> String {
+        let entities = self.entities();
+
+        let mut output = String::new();
+        output.push_str(&link_directive());
+        output.push_str("\n\n");
+        output.push_str(&self.print_sdl());
+
+        // `_Entity` is a union of every keyed type; omit it entirely when the
+        // subgraph defines no entities, matching Apollo's behavior.
+        if !entities.is_empty() {
+            let members: Vec<String> = entities
+                .iter()
+                .map(|entity| self.object_name(entity.object.id).to_string())
+                .collect();
+            output.push_str(&format!("\nunion _Entity = {}\n", members.join(" | ")));
+        }
+
+        output.push_str(SERVICE_AND_ANY_SDL);
+        output.push_str(&self.federation_root_fields(!entities.is_empty()));
+        output
+    }
+
+    /// Renders the `_entities`/`_service` fields that are grafted onto the
+    /// query root. `_entities` is only emitted when the subgraph has entities.
+    fn federation_root_fields(&self, has_entities: bool) -> String {
+        let query = self
+            .query_type()
+            .map(|query| self.type_name(query).to_string())
+            .unwrap_or_else(|| "Query".to_string());
+        let mut body = String::new();
+        if has_entities {
+            body.push_str("  _entities(representations: [_Any!]!): [_Entity]!\n");
+        }
+        body.push_str("  _service: _Service!\n");
+        format!("\nextend type {query} {{\n{body}}}\n")
+    }
+}
+
+const SERVICE_AND_ANY_SDL: &str = "\nscalar _Any\n\ntype _Service {\n  sdl: String\n}\n";
+
+fn link_directive() -> String {
+    let imports = FEDERATION_DIRECTIVES
+        .iter()
+        .filter(|name| **name != "link")
+        .map(|name| format!("\"@{name}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("extend schema @link(url: \"{FEDERATION_LINK_URL}\", import: [{imports}])")
+}
+
+/// Extracts the structured `@key` applications from a type's directives.
+fn key_directives(directives: &[Directive]) -> Vec<KeyDirective> {
+    directives
+        .iter()
+        .filter(|directive| directive.name == "key")
+        .map(|directive| KeyDirective {
+            fields: string_arg(directive, "fields").unwrap_or_default(),
+            resolvable: directive
+                .arguments
+                .iter()
+                .find(|argument| argument.name == "resolvable")
+                .map(|argument| argument.value.to_string() != "false")
+                .unwrap_or(true),
+        })
+        .collect()
+}
+
+fn has_directive(directives: &[Directive], name: &str) -> bool {
+    directives.iter().any(|directive| directive.name == name)
+}
+
+/// The `fields` selection set of a `@provides`/`@requires` application.
+fn directive_field_set(directives: &[Directive], name: &str) -> Option<String> {
+    directives
+        .iter()
+        .find(|directive| directive.name == name)
+        .and_then(|directive| string_arg(directive, "fields"))
+}
+
+/// A string-valued argument of the first matching directive application.
+fn directive_string_arg(directives: &[Directive], name: &str, argument: &str) -> Option<String> {
+    directives
+        .iter()
+        .find(|directive| directive.name == name)
+        .and_then(|directive| string_arg(directive, argument))
+}
+
+/// Every `@tag(name:)` applied across the directive list.
+fn tag_directives(directives: &[Directive]) -> Vec<String> {
+    directives
+        .iter()
+        .filter(|directive| directive.name == "tag")
+        .filter_map(|directive| string_arg(directive, "name"))
+        .collect()
+}
+
+fn string_arg(directive: &Directive, name: &str) -> Option<String> {
+    directive
+        .arguments
+        .iter()
+        .find(|argument| argument.name == name)
+        .map(|argument| argument.value.to_string())
+}
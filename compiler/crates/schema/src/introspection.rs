@@ -0,0 +1,1015 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Builds a [`Schema`] from a standard GraphQL `__schema` introspection
+//! document. The JSON is deserialized into an intermediate model that mirrors
+//! the introspection type system, lowered to the same `graphql_syntax` AST
+//! nodes that the SDL parser produces, and handed to [`Schema::build`] so that
+//! interning of `ObjectID`/`FieldID`/etc. is shared with the SDL code path.
+
+use graphql_syntax::type_system_node::{
+    Directive as DirectiveDefinition, DirectiveLocation, EnumTypeDefinition, EnumValueDefinition,
+    FieldDefinition, InputObjectTypeDefinition, InputValueDefinition, InterfaceTypeDefinition,
+    ObjectTypeDefinition, ScalarTypeDefinition, SchemaDefinition, Type as AstType,
+    TypeSystemDefinition, UnionTypeDefinition, Value as AstValue,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::definitions::{
+    Argument, Directive, Enum, Field, InputObject, Interface, Object, Scalar, Schema,
+    TypeReference, Union,
+};
+use crate::errors::{Result, SchemaError};
+use crate::{parse_definitions, BUILTINS};
+
+/// Builds a [`Schema`] from the JSON body of an introspection query. The input
+/// may be either the bare `__schema` object or the conventional
+/// `{ "data": { "__schema": … } }` / `{ "__schema": … }` envelope.
+pub fn build_schema_from_introspection(json: &str) -> Result<Schema> {
+    let document: IntrospectionDocument =
+        serde_json::from_str(json).map_err(SchemaError::InvalidIntrospection)?;
+    let schema = document.into_schema();
+
+    let mut definitions = parse_definitions(BUILTINS)?;
+    definitions.push(schema.schema_definition());
+    definitions.extend(schema.type_definitions()?);
+    definitions.extend(schema.directive_definitions()?);
+
+    Schema::build(&definitions, &[])
+}
+
+/// Accepts the bare `__schema` object or either of the common envelopes.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IntrospectionDocument {
+    Data { data: SchemaEnvelope },
+    Envelope(SchemaEnvelope),
+    Bare(IntrospectionSchema),
+}
+
+#[derive(Deserialize)]
+struct SchemaEnvelope {
+    #[serde(rename = "__schema")]
+    schema: IntrospectionSchema,
+}
+
+impl IntrospectionDocument {
+    fn into_schema(self) -> IntrospectionSchema {
+        match self {
+            IntrospectionDocument::Data { data } => data.schema,
+            IntrospectionDocument::Envelope(envelope) => envelope.schema,
+            IntrospectionDocument::Bare(schema) => schema,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionSchema {
+    #[serde(default)]
+    query_type: Option<NamedTypeRef>,
+    #[serde(default)]
+    mutation_type: Option<NamedTypeRef>,
+    #[serde(default)]
+    subscription_type: Option<NamedTypeRef>,
+    types: Vec<IntrospectionType>,
+    #[serde(default)]
+    directives: Vec<IntrospectionDirective>,
+}
+
+#[derive(Deserialize)]
+struct NamedTypeRef {
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionType {
+    kind: TypeKind,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    fields: Option<Vec<IntrospectionField>>,
+    #[serde(default)]
+    interfaces: Option<Vec<TypeRef>>,
+    #[serde(default)]
+    possible_types: Option<Vec<TypeRef>>,
+    #[serde(default)]
+    enum_values: Option<Vec<IntrospectionEnumValue>>,
+    #[serde(default)]
+    input_fields: Option<Vec<IntrospectionInputValue>>,
+}
+
+#[derive(Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum TypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+    List,
+    NonNull,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionField {
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    args: Vec<IntrospectionInputValue>,
+    #[serde(rename = "type")]
+    type_ref: TypeRef,
+    #[serde(default)]
+    is_deprecated: bool,
+    #[serde(default)]
+    deprecation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionInputValue {
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(rename = "type")]
+    type_ref: TypeRef,
+    #[serde(default)]
+    default_value: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionEnumValue {
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    is_deprecated: bool,
+    #[serde(default)]
+    deprecation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionDirective {
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    locations: Vec<String>,
+    #[serde(default)]
+    args: Vec<IntrospectionInputValue>,
+    #[serde(default)]
+    is_repeatable: bool,
+}
+
+/// The recursive `{ kind, name, ofType }` wrapper shared by every type
+/// position in the introspection document.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TypeRef {
+    kind: TypeKind,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    of_type: Option<Box<TypeRef>>,
+}
+
+impl TypeRef {
+    /// Flattens the introspection wrapper into the crate's AST type, with
+    /// `NON_NULL` wrapping a `LIST` wrapping a named type, matching the SDL
+    /// grammar's nesting. Malformed references (a wrapper missing `ofType`, a
+    /// named reference missing `name`) surface as
+    /// [`SchemaError::InvalidIntrospection`] rather than panicking, since the
+    /// JSON originates from untrusted external input.
+    fn to_ast_type(&self) -> Result<AstType> {
+        match self.kind {
+            TypeKind::NonNull => Ok(AstType::NonNull(Box::new(
+                self.of_type_ref("NON_NULL")?.to_ast_type()?,
+            ))),
+            TypeKind::List => Ok(AstType::List(Box::new(
+                self.of_type_ref("LIST")?.to_ast_type()?,
+            ))),
+            _ => Ok(AstType::Named(self.name.clone().ok_or_else(|| {
+                malformed_introspection("named type reference is missing a name")
+            })?)),
+        }
+    }
+
+    fn of_type_ref(&self, wrapper: &str) -> Result<&TypeRef> {
+        self.of_type.as_deref().ok_or_else(|| {
+            malformed_introspection(&format!("{wrapper} type reference is missing ofType"))
+        })
+    }
+}
+
+/// Builds an [`InvalidIntrospection`] error for a structurally malformed (but
+/// syntactically valid) introspection document.
+///
+/// [`InvalidIntrospection`]: SchemaError::InvalidIntrospection
+fn malformed_introspection(message: &str) -> SchemaError {
+    use serde::de::Error as _;
+    SchemaError::InvalidIntrospection(serde_json::Error::custom(message))
+}
+
+impl IntrospectionSchema {
+    fn schema_definition(&self) -> TypeSystemDefinition {
+        TypeSystemDefinition::SchemaDefinition(SchemaDefinition {
+            directives: Vec::new(),
+            query: self.query_type.as_ref().map(|t| t.name.clone()),
+            mutation: self.mutation_type.as_ref().map(|t| t.name.clone()),
+            subscription: self.subscription_type.as_ref().map(|t| t.name.clone()),
+        })
+    }
+
+    fn type_definitions(&self) -> Result<Vec<TypeSystemDefinition>> {
+        self.types
+            .iter()
+            .filter(|t| !is_introspection_meta_type(t.name.as_deref()))
+            .filter_map(|t| t.to_definition().transpose())
+            .collect()
+    }
+
+    fn directive_definitions(&self) -> Result<Vec<TypeSystemDefinition>> {
+        self.directives
+            .iter()
+            .filter(|directive| !is_builtin_directive(&directive.name))
+            .map(IntrospectionDirective::to_definition)
+            .collect()
+    }
+}
+
+/// The `__Schema`, `__Type`, `__Field`, … meta-types are synthesized by
+/// `Schema::build`; skipping them keeps introspection round-trips stable.
+fn is_introspection_meta_type(name: Option<&str>) -> bool {
+    matches!(name, Some(name) if name.starts_with("__"))
+}
+
+impl IntrospectionType {
+    fn to_definition(&self) -> Result<Option<TypeSystemDefinition>> {
+        let name = match self.name.clone() {
+            Some(name) => name,
+            None => return Ok(None),
+        };
+        let description = self.description.clone();
+        Ok(Some(match self.kind {
+            TypeKind::Scalar => {
+                // Built-in scalars are folded back onto `BUILTINS`.
+                if is_builtin_scalar(&name) {
+                    return Ok(None);
+                }
+                TypeSystemDefinition::ScalarTypeDefinition(ScalarTypeDefinition {
+                    name,
+                    description,
+                    directives: Vec::new(),
+                })
+            }
+            TypeKind::Object => TypeSystemDefinition::ObjectTypeDefinition(ObjectTypeDefinition {
+                name,
+                description,
+                interfaces: self.interface_names(),
+                fields: self.field_definitions()?,
+                directives: Vec::new(),
+            }),
+            TypeKind::Interface => {
+                TypeSystemDefinition::InterfaceTypeDefinition(InterfaceTypeDefinition {
+                    name,
+                    description,
+                    interfaces: self.interface_names(),
+                    fields: self.field_definitions()?,
+                    directives: Vec::new(),
+                })
+            }
+            TypeKind::Union => TypeSystemDefinition::UnionTypeDefinition(UnionTypeDefinition {
+                name,
+                description,
+                members: self
+                    .possible_types
+                    .iter()
+                    .flatten()
+                    .filter_map(|t| t.name.clone())
+                    .collect(),
+                directives: Vec::new(),
+            }),
+            TypeKind::Enum => TypeSystemDefinition::EnumTypeDefinition(EnumTypeDefinition {
+                name,
+                description,
+                values: self
+                    .enum_values
+                    .iter()
+                    .flatten()
+                    .map(IntrospectionEnumValue::to_definition)
+                    .collect(),
+                directives: Vec::new(),
+            }),
+            TypeKind::InputObject => {
+                TypeSystemDefinition::InputObjectTypeDefinition(InputObjectTypeDefinition {
+                    name,
+                    description,
+                    fields: self
+                        .input_fields
+                        .iter()
+                        .flatten()
+                        .map(IntrospectionInputValue::to_definition)
+                        .collect::<Result<Vec<_>>>()?,
+                    directives: Vec::new(),
+                })
+            }
+            // Wrapper kinds never appear at the top level of `types`.
+            TypeKind::List | TypeKind::NonNull => return Ok(None),
+        }))
+    }
+
+    fn interface_names(&self) -> Vec<String> {
+        self.interfaces
+            .iter()
+            .flatten()
+            .filter_map(|t| t.name.clone())
+            .collect()
+    }
+
+    fn field_definitions(&self) -> Result<Vec<FieldDefinition>> {
+        self.fields
+            .iter()
+            .flatten()
+            .map(IntrospectionField::to_definition)
+            .collect()
+    }
+}
+
+impl IntrospectionField {
+    fn to_definition(&self) -> Result<FieldDefinition> {
+        Ok(FieldDefinition {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            arguments: self
+                .args
+                .iter()
+                .map(IntrospectionInputValue::to_definition)
+                .collect::<Result<Vec<_>>>()?,
+            type_: self.type_ref.to_ast_type()?,
+            directives: deprecation_directives(self.is_deprecated, &self.deprecation_reason),
+        })
+    }
+}
+
+impl IntrospectionInputValue {
+    fn to_definition(&self) -> Result<InputValueDefinition> {
+        Ok(InputValueDefinition {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            type_: self.type_ref.to_ast_type()?,
+            // The introspection `defaultValue` is a serialized GraphQL literal;
+            // parse it back into the AST `Value` the SDL path would produce.
+            default_value: self.default_value.as_deref().map(parse_const_value),
+            directives: Vec::new(),
+        })
+    }
+}
+
+impl IntrospectionDirective {
+    fn to_definition(&self) -> Result<TypeSystemDefinition> {
+        Ok(TypeSystemDefinition::DirectiveDefinition(DirectiveDefinition {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            arguments: self
+                .args
+                .iter()
+                .map(IntrospectionInputValue::to_definition)
+                .collect::<Result<Vec<_>>>()?,
+            repeatable: self.is_repeatable,
+            locations: self
+                .locations
+                .iter()
+                .filter_map(|location| parse_directive_location(location))
+                .collect(),
+        }))
+    }
+}
+
+impl IntrospectionEnumValue {
+    fn to_definition(&self) -> EnumValueDefinition {
+        EnumValueDefinition {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            directives: deprecation_directives(self.is_deprecated, &self.deprecation_reason),
+        }
+    }
+}
+
+/// Reconstructs the `@deprecated(reason: …)` application that the SDL parser
+/// would have produced for a deprecated field or enum value.
+fn deprecation_directives(
+    is_deprecated: bool,
+    reason: &Option<String>,
+) -> Vec<graphql_syntax::type_system_node::Directive> {
+    use graphql_syntax::type_system_node::{Argument, Directive, Value};
+
+    if !is_deprecated {
+        return Vec::new();
+    }
+    let arguments = match reason {
+        Some(reason) => vec![Argument {
+            name: "reason".to_string(),
+            value: Value::String(reason.clone()),
+        }],
+        None => Vec::new(),
+    };
+    vec![Directive {
+        name: "deprecated".to_string(),
+        arguments,
+    }]
+}
+
+fn is_builtin_scalar(name: &str) -> bool {
+    matches!(name, "Int" | "Float" | "String" | "Boolean" | "ID")
+}
+
+/// The directives every GraphQL server exposes; they live in `BUILTINS` and so
+/// must not be re-declared from the introspection document.
+fn is_builtin_directive(name: &str) -> bool {
+    matches!(name, "skip" | "include" | "deprecated" | "specifiedBy")
+}
+
+fn parse_directive_location(location: &str) -> Option<DirectiveLocation> {
+    Some(match location {
+        "QUERY" => DirectiveLocation::Query,
+        "MUTATION" => DirectiveLocation::Mutation,
+        "SUBSCRIPTION" => DirectiveLocation::Subscription,
+        "FIELD" => DirectiveLocation::Field,
+        "FRAGMENT_DEFINITION" => DirectiveLocation::FragmentDefinition,
+        "FRAGMENT_SPREAD" => DirectiveLocation::FragmentSpread,
+        "INLINE_FRAGMENT" => DirectiveLocation::InlineFragment,
+        "VARIABLE_DEFINITION" => DirectiveLocation::VariableDefinition,
+        "SCHEMA" => DirectiveLocation::Schema,
+        "SCALAR" => DirectiveLocation::Scalar,
+        "OBJECT" => DirectiveLocation::Object,
+        "FIELD_DEFINITION" => DirectiveLocation::FieldDefinition,
+        "ARGUMENT_DEFINITION" => DirectiveLocation::ArgumentDefinition,
+        "INTERFACE" => DirectiveLocation::Interface,
+        "UNION" => DirectiveLocation::Union,
+        "ENUM" => DirectiveLocation::Enum,
+        "ENUM_VALUE" => DirectiveLocation::EnumValue,
+        "INPUT_OBJECT" => DirectiveLocation::InputObject,
+        "INPUT_FIELD_DEFINITION" => DirectiveLocation::InputFieldDefinition,
+        _ => return None,
+    })
+}
+
+/// Parses a serialized const GraphQL literal (as it appears in an
+/// introspection `defaultValue`) into the AST [`Value`]. Unrecognized input
+/// degrades to an enum/`Value::Enum` token so a best-effort value survives.
+fn parse_const_value(literal: &str) -> AstValue {
+    ValueParser::new(literal).parse()
+}
+
+struct ValueParser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl<'a> ValueParser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            chars: input.chars().peekable(),
+        }
+    }
+
+    fn parse(mut self) -> AstValue {
+        self.skip_whitespace();
+        self.parse_value()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.chars.peek(), Some(c) if c.is_whitespace() || *c == ',') {
+            self.chars.next();
+        }
+    }
+
+    fn parse_value(&mut self) -> AstValue {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some('"') => self.parse_string(),
+            Some('[') => self.parse_list(),
+            Some('{') => self.parse_object(),
+            Some(c) if *c == '-' || c.is_ascii_digit() => self.parse_number(),
+            _ => self.parse_name_like(),
+        }
+    }
+
+    fn parse_string(&mut self) -> AstValue {
+        self.chars.next(); // opening quote
+        let mut out = String::new();
+        while let Some(c) = self.chars.next() {
+            match c {
+                '"' => break,
+                '\\' => {
+                    if let Some(escaped) = self.chars.next() {
+                        out.push(escaped);
+                    }
+                }
+                _ => out.push(c),
+            }
+        }
+        AstValue::String(out)
+    }
+
+    fn parse_list(&mut self) -> AstValue {
+        self.chars.next(); // [
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek() {
+                Some(']') | None => {
+                    self.chars.next();
+                    break;
+                }
+                _ => items.push(self.parse_value()),
+            }
+        }
+        AstValue::List(items)
+    }
+
+    fn parse_object(&mut self) -> AstValue {
+        self.chars.next(); // {
+        let mut fields = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek() {
+                Some('}') | None => {
+                    self.chars.next();
+                    break;
+                }
+                _ => {
+                    let name = self.take_while(|c| c.is_alphanumeric() || c == '_');
+                    self.skip_whitespace();
+                    if self.chars.peek() == Some(&':') {
+                        self.chars.next();
+                    }
+                    let value = self.parse_value();
+                    fields.push((name, value));
+                }
+            }
+        }
+        AstValue::Object(fields)
+    }
+
+    fn parse_number(&mut self) -> AstValue {
+        let token = self.take_while(|c| {
+            c.is_ascii_digit() || c == '-' || c == '+' || c == '.' || c == 'e' || c == 'E'
+        });
+        if token.contains('.') || token.contains('e') || token.contains('E') {
+            AstValue::Float(token.parse().unwrap_or(0.0))
+        } else {
+            AstValue::Int(token.parse().unwrap_or(0))
+        }
+    }
+
+    fn parse_name_like(&mut self) -> AstValue {
+        let token = self.take_while(|c| c.is_alphanumeric() || c == '_');
+        match token.as_str() {
+            "true" => AstValue::Boolean(true),
+            "false" => AstValue::Boolean(false),
+            "null" => AstValue::Null,
+            _ => AstValue::Enum(token),
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+        let mut out = String::new();
+        while let Some(c) = self.chars.peek() {
+            if predicate(*c) {
+                out.push(*c);
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+        out
+    }
+}
+
+impl Schema {
+    /// Produces a complete `__schema` introspection document from the interned
+    /// type tables: every user type as a `__Type`, the synthetic meta-types,
+    /// the `directives` list, and the `query`/`mutation`/`subscription` root
+    /// references. This is the inverse of [`build_schema_from_introspection`]
+    /// and lets tools serve introspection without a resolver runtime.
+    pub fn to_introspection(&self) -> Value {
+        let mut types: Vec<Value> = Vec::new();
+        types.extend(self.scalars().map(|scalar| self.scalar_introspection(scalar)));
+        types.extend(self.objects().map(|object| self.object_introspection(object)));
+        types.extend(
+            self.interfaces()
+                .map(|interface| self.interface_introspection(interface)),
+        );
+        types.extend(self.unions().map(|union| self.union_introspection(union)));
+        types.extend(self.enums().map(|enum_| self.enum_introspection(enum_)));
+        types.extend(
+            self.input_objects()
+                .map(|input| self.input_object_introspection(input)),
+        );
+        types.extend(meta_types());
+
+        json!({
+            "__schema": {
+                "queryType": self.root_type_ref(self.query_type()),
+                "mutationType": self.root_type_ref(self.mutation_type()),
+                "subscriptionType": self.root_type_ref(self.subscription_type()),
+                "types": types,
+                "directives": self
+                    .directives()
+                    .map(|directive| self.directive_introspection(directive))
+                    .collect::<Vec<_>>(),
+            }
+        })
+    }
+
+    fn root_type_ref(&self, type_: Option<crate::definitions::Type>) -> Value {
+        match type_ {
+            Some(type_) => json!({ "name": self.type_name(type_).to_string() }),
+            None => Value::Null,
+        }
+    }
+
+    fn scalar_introspection(&self, scalar: &Scalar) -> Value {
+        json!({
+            "kind": "SCALAR",
+            "name": self.scalar_name(scalar.id).to_string(),
+            "description": scalar.description,
+        })
+    }
+
+    fn object_introspection(&self, object: &Object) -> Value {
+        json!({
+            "kind": "OBJECT",
+            "name": self.object_name(object.id).to_string(),
+            "description": object.description,
+            "fields": self.fields_introspection(&object.fields),
+            "interfaces": object
+                .interfaces
+                .iter()
+                .map(|id| named_ref("INTERFACE", self.interface_name(*id).as_ref()))
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    fn interface_introspection(&self, interface: &Interface) -> Value {
+        json!({
+            "kind": "INTERFACE",
+            "name": self.interface_name(interface.id).to_string(),
+            "description": interface.description,
+            "fields": self.fields_introspection(&interface.fields),
+            "interfaces": interface
+                .interfaces
+                .iter()
+                .map(|id| named_ref("INTERFACE", self.interface_name(*id).as_ref()))
+                .collect::<Vec<_>>(),
+            "possibleTypes": self
+                .implementors(interface.id)
+                .map(|id| named_ref("OBJECT", self.object_name(id).as_ref()))
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    fn union_introspection(&self, union: &Union) -> Value {
+        json!({
+            "kind": "UNION",
+            "name": self.union_name(union.id).to_string(),
+            "description": union.description,
+            "possibleTypes": union
+                .members
+                .iter()
+                .map(|id| named_ref("OBJECT", self.object_name(*id).as_ref()))
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    fn enum_introspection(&self, enum_: &Enum) -> Value {
+        json!({
+            "kind": "ENUM",
+            "name": self.enum_name(enum_.id).to_string(),
+            "description": enum_.description,
+            "enumValues": enum_
+                .values
+                .iter()
+                .map(|value| {
+                    let (is_deprecated, reason) = deprecation(&value.directives);
+                    json!({
+                        "name": value.value.to_string(),
+                        "description": value.description,
+                        "isDeprecated": is_deprecated,
+                        "deprecationReason": reason,
+                    })
+                })
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    fn input_object_introspection(&self, input: &InputObject) -> Value {
+        json!({
+            "kind": "INPUT_OBJECT",
+            "name": self.input_object_name(input.id).to_string(),
+            "description": input.description,
+            "inputFields": input
+                .fields
+                .iter()
+                .map(|field| {
+                    json!({
+                        "name": field.name.to_string(),
+                        "description": field.description,
+                        "type": self.type_reference_introspection(&field.type_),
+                        "defaultValue": field.default_value.as_ref().map(|value| value.to_string()),
+                    })
+                })
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    fn fields_introspection(&self, fields: &[crate::definitions::FieldID]) -> Value {
+        Value::Array(
+            fields
+                .iter()
+                .map(|id| self.field_introspection(self.field(*id)))
+                .collect(),
+        )
+    }
+
+    fn field_introspection(&self, field: &Field) -> Value {
+        let (is_deprecated, reason) = deprecation(&field.directives);
+        json!({
+            "name": field.name.to_string(),
+            "description": field.description,
+            "args": field
+                .arguments
+                .iter()
+                .map(|argument| self.argument_introspection(argument))
+                .collect::<Vec<_>>(),
+            "type": self.type_reference_introspection(&field.type_),
+            "isDeprecated": is_deprecated,
+            "deprecationReason": reason,
+        })
+    }
+
+    fn argument_introspection(&self, argument: &Argument) -> Value {
+        json!({
+            "name": argument.name.to_string(),
+            "type": self.type_reference_introspection(&argument.type_),
+            "defaultValue": argument.default_value.as_ref().map(|value| value.to_string()),
+        })
+    }
+
+    fn directive_introspection(&self, directive: &crate::DirectiveDefinition) -> Value {
+        json!({
+            "name": directive.name.to_string(),
+            "description": directive.description,
+            "isRepeatable": directive.repeatable,
+            "locations": directive
+                .locations
+                .iter()
+                .map(|location| location.to_string())
+                .collect::<Vec<_>>(),
+            "args": directive
+                .arguments
+                .iter()
+                .map(|argument| {
+                    json!({
+                        "name": argument.name.to_string(),
+                        "type": self.type_reference_introspection(&argument.type_),
+                        "defaultValue": argument
+                            .default_value
+                            .as_ref()
+                            .map(|value| value.to_string()),
+                    })
+                })
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    /// Reconstructs the recursive `{ kind, name, ofType }` wrapper from a
+    /// flattened [`TypeReference`].
+    fn type_reference_introspection(&self, type_: &TypeReference) -> Value {
+        match type_ {
+            TypeReference::Named(inner) => {
+                named_ref(self.type_kind(*inner), self.type_name(*inner).as_ref())
+            }
+            TypeReference::NonNull(inner) => json!({
+                "kind": "NON_NULL",
+                "name": Value::Null,
+                "ofType": self.type_reference_introspection(inner),
+            }),
+            TypeReference::List(inner) => json!({
+                "kind": "LIST",
+                "name": Value::Null,
+                "ofType": self.type_reference_introspection(inner),
+            }),
+        }
+    }
+
+    /// The `__TypeKind` of a named type, for the leaf of a type reference.
+    fn type_kind(&self, type_: crate::definitions::Type) -> &'static str {
+        use crate::definitions::Type::*;
+        match type_ {
+            Scalar(_) => "SCALAR",
+            Object(_) => "OBJECT",
+            Interface(_) => "INTERFACE",
+            Union(_) => "UNION",
+            Enum(_) => "ENUM",
+            InputObject(_) => "INPUT_OBJECT",
+        }
+    }
+}
+
+fn named_ref(kind: &str, name: &str) -> Value {
+    json!({ "kind": kind, "name": name, "ofType": Value::Null })
+}
+
+/// Extracts `(isDeprecated, deprecationReason)` from a `@deprecated`
+/// application, if present.
+fn deprecation(directives: &[Directive]) -> (bool, Option<String>) {
+    match directives.iter().find(|d| d.name == "deprecated") {
+        Some(directive) => {
+            let reason = directive
+                .arguments
+                .iter()
+                .find(|argument| argument.name == "reason")
+                .map(|argument| argument.value.to_string());
+            (true, reason)
+        }
+        None => (false, None),
+    }
+}
+
+/// The introspection meta-types that `Schema::build` synthesizes. They are not
+/// present in the interned tables, so they are emitted here with the field and
+/// enum-value shapes the spec's introspection schema mandates, so that a client
+/// introspecting the introspection system sees a complete document.
+fn meta_types() -> Vec<Value> {
+    let string = || named_ref("SCALAR", "String");
+    let boolean = || named_ref("SCALAR", "Boolean");
+    let type_ = || named_ref("OBJECT", "__Type");
+
+    vec![
+        meta_object(
+            "__Schema",
+            vec![
+                meta_field("description", string()),
+                meta_field("types", nn(list(nn(type_())))),
+                meta_field("queryType", nn(type_())),
+                meta_field("mutationType", type_()),
+                meta_field("subscriptionType", type_()),
+                meta_field("directives", nn(list(nn(named_ref("OBJECT", "__Directive"))))),
+            ],
+        ),
+        meta_object(
+            "__Type",
+            vec![
+                meta_field("kind", nn(named_ref("ENUM", "__TypeKind"))),
+                meta_field("name", string()),
+                meta_field("description", string()),
+                meta_field("fields", list(nn(named_ref("OBJECT", "__Field")))),
+                meta_field("interfaces", list(nn(type_()))),
+                meta_field("possibleTypes", list(nn(type_()))),
+                meta_field("enumValues", list(nn(named_ref("OBJECT", "__EnumValue")))),
+                meta_field("inputFields", list(nn(named_ref("OBJECT", "__InputValue")))),
+                meta_field("ofType", type_()),
+                meta_field("specifiedByURL", string()),
+            ],
+        ),
+        meta_object(
+            "__Field",
+            vec![
+                meta_field("name", nn(string())),
+                meta_field("description", string()),
+                meta_field("args", nn(list(nn(named_ref("OBJECT", "__InputValue"))))),
+                meta_field("type", nn(type_())),
+                meta_field("isDeprecated", nn(boolean())),
+                meta_field("deprecationReason", string()),
+            ],
+        ),
+        meta_object(
+            "__InputValue",
+            vec![
+                meta_field("name", nn(string())),
+                meta_field("description", string()),
+                meta_field("type", nn(type_())),
+                meta_field("defaultValue", string()),
+            ],
+        ),
+        meta_object(
+            "__EnumValue",
+            vec![
+                meta_field("name", nn(string())),
+                meta_field("description", string()),
+                meta_field("isDeprecated", nn(boolean())),
+                meta_field("deprecationReason", string()),
+            ],
+        ),
+        meta_object(
+            "__Directive",
+            vec![
+                meta_field("name", nn(string())),
+                meta_field("description", string()),
+                meta_field("isRepeatable", nn(boolean())),
+                meta_field(
+                    "locations",
+                    nn(list(nn(named_ref("ENUM", "__DirectiveLocation")))),
+                ),
+                meta_field("args", nn(list(nn(named_ref("OBJECT", "__InputValue"))))),
+            ],
+        ),
+        meta_enum(
+            "__TypeKind",
+            &[
+                "SCALAR",
+                "OBJECT",
+                "INTERFACE",
+                "UNION",
+                "ENUM",
+                "INPUT_OBJECT",
+                "LIST",
+                "NON_NULL",
+            ],
+        ),
+        meta_enum(
+            "__DirectiveLocation",
+            &[
+                "QUERY",
+                "MUTATION",
+                "SUBSCRIPTION",
+                "FIELD",
+                "FRAGMENT_DEFINITION",
+                "FRAGMENT_SPREAD",
+                "INLINE_FRAGMENT",
+                "VARIABLE_DEFINITION",
+                "SCHEMA",
+                "SCALAR",
+                "OBJECT",
+                "FIELD_DEFINITION",
+                "ARGUMENT_DEFINITION",
+                "INTERFACE",
+                "UNION",
+                "ENUM",
+                "ENUM_VALUE",
+                "INPUT_OBJECT",
+                "INPUT_FIELD_DEFINITION",
+            ],
+        ),
+    ]
+}
+
+fn meta_object(name: &str, fields: Vec<Value>) -> Value {
+    json!({
+        "kind": "OBJECT",
+        "name": name,
+        "description": Value::Null,
+        "fields": fields,
+        "interfaces": [],
+    })
+}
+
+fn meta_enum(name: &str, values: &[&str]) -> Value {
+    json!({
+        "kind": "ENUM",
+        "name": name,
+        "description": Value::Null,
+        "enumValues": values
+            .iter()
+            .map(|value| json!({
+                "name": value,
+                "description": Value::Null,
+                "isDeprecated": false,
+                "deprecationReason": Value::Null,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn meta_field(name: &str, type_: Value) -> Value {
+    json!({
+        "name": name,
+        "description": Value::Null,
+        "args": [],
+        "type": type_,
+        "isDeprecated": false,
+        "deprecationReason": Value::Null,
+    })
+}
+
+fn nn(inner: Value) -> Value {
+    json!({ "kind": "NON_NULL", "name": Value::Null, "ofType": inner })
+}
+
+fn list(inner: Value) -> Value {
+    json!({ "kind": "LIST", "name": Value::Null, "ofType": inner })
+}
@@ -14,8 +14,12 @@
 
 mod definitions;
 mod errors;
+mod extensions;
+mod federation;
+mod introspection;
 mod lexer;
 mod parser;
+mod printer;
 mod token;
 
 pub use definitions::{
@@ -24,6 +28,8 @@ pub use definitions::{
     ObjectID, Scalar, ScalarID, Schema, Type, TypeReference, TypeWithFields, Union, UnionID,
 };
 pub use errors::{Result, SchemaError};
+pub use federation::{Entity, FieldFederation, KeyDirective, TypeFederation};
+pub use introspection::build_schema_from_introspection;
 pub use graphql_syntax::type_system_node::{
     Directive as DirectiveDefinition, DirectiveLocation, FieldDefinition, InputValueDefinition,
     Type as AstType, TypeSystemDefinition, Value as AstValue,
@@ -54,6 +60,13 @@ pub fn build_schema_with_extensions<T: AsRef<str>, U: AsRef<str>>(
         extension_definitions.extend(parse_definitions(extension_sdl.as_ref())?);
     }
 
+    // Fold any `extend` forms onto the base types they augment before
+    // interning. Extensions may target a base declared in either stream, but
+    // each base keeps its stream so client definitions are still interned as
+    // schema extensions (`is_extension = true`).
+    let (server_definitions, extension_definitions) =
+        extensions::merge_extensions(server_definitions, extension_definitions)?;
+
     Schema::build(&server_definitions, &extension_definitions)
 }
 
@@ -62,3 +75,106 @@ pub fn parse_definitions(input: &str) -> Result<Vec<TypeSystemDefinition>> {
     let parser = Parser::new(lexer);
     parser.parse_schema_document()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SERVER_SDL: &str = r#"
+        type Query {
+            user(id: ID!): User
+            status: Status!
+        }
+
+        "A registered user."
+        type User implements Node {
+            id: ID!
+            name: String!
+            legacyId: Int @deprecated(reason: "use id")
+        }
+
+        interface Node {
+            id: ID!
+        }
+
+        enum Status {
+            ACTIVE
+            DISABLED
+        }
+
+        input UserFilter {
+            limit: Int = 10
+        }
+    "#;
+
+    #[test]
+    fn extend_type_adds_fields_to_base() {
+        let schema = build_schema_with_extensions(
+            &[SERVER_SDL],
+            &["extend type User { nickname: String }"],
+        )
+        .unwrap();
+        // The extension field and the base fields coexist on the merged type.
+        let printed = schema.print_sdl();
+        assert!(printed.contains("nickname: String"));
+        assert!(printed.contains("name: String!"));
+    }
+
+    #[test]
+    fn print_sdl_round_trips() {
+        let schema = build_schema(SERVER_SDL).unwrap();
+        let printed = schema.print_sdl();
+        let reparsed = build_schema(&printed).unwrap();
+        // Printing the reparsed schema must be a fixed point.
+        assert_eq!(printed, reparsed.print_sdl());
+    }
+
+    #[test]
+    fn build_schema_from_introspection_errors_on_malformed_type_ref() {
+        // A NON_NULL wrapper with no `ofType` is structurally invalid and must
+        // surface as an error rather than panicking.
+        let json = r#"{"__schema":{"queryType":{"name":"Query"},"types":[
+            {"kind":"OBJECT","name":"Query","fields":[
+                {"name":"x","args":[],"type":{"kind":"NON_NULL"}}
+            ],"interfaces":[]}
+        ],"directives":[]}}"#;
+        assert!(matches!(
+            build_schema_from_introspection(json),
+            Err(SchemaError::InvalidIntrospection(_))
+        ));
+    }
+
+    #[test]
+    fn introspection_is_the_inverse_of_sdl() {
+        let schema = build_schema(SERVER_SDL).unwrap();
+        let introspection = schema.to_introspection().to_string();
+        let rebuilt = build_schema_from_introspection(&introspection).unwrap();
+        // Round-tripping through introspection preserves the printed schema.
+        assert_eq!(schema.print_sdl(), rebuilt.print_sdl());
+    }
+
+    #[test]
+    fn federation_sdl_exposes_entities() {
+        let schema = build_schema(
+            r#"
+            type Query { root: String }
+            type Product @key(fields: "upc") {
+                upc: String!
+                name: String
+            }
+            "#,
+        )
+        .unwrap();
+
+        let entities = schema.entities();
+        assert_eq!(entities.len(), 1);
+        assert_eq!(entities[0].keys[0].fields, "upc");
+
+        let sdl = schema.to_federation_sdl();
+        assert!(sdl.contains("union _Entity = Product"));
+        assert!(sdl.contains("_entities(representations: [_Any!]!): [_Entity]!"));
+        assert!(sdl.contains("_service: _Service!"));
+        // Plain printing must not leak the synthesized federation machinery.
+        assert!(!schema.print_sdl().contains("_Entity"));
+    }
+}
@@ -0,0 +1,330 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Renders a [`Schema`] back out to spec-compliant SDL. Output is stably
+//! ordered — types grouped by kind (scalars, objects, interfaces, unions,
+//! enums, input objects) and alphabetical within each kind, members in
+//! declaration order — so that snapshots are diffable and
+//! `build_schema(&schema.print_sdl())` yields an equivalent schema.
+//! Directive definitions are not printed, so a schema that declares its own
+//! directives does not round-trip them.
+
+use std::fmt::Write;
+
+use crate::definitions::{
+    Argument, ArgumentValue, Directive, DirectiveValue, Enum, Field, InputObject, Interface,
+    Object, Scalar, Schema, TypeReference, Union,
+};
+
+impl Schema {
+    /// Serializes the schema's types and schema definition to canonical SDL;
+    /// directive definitions are not included.
+    pub fn print_sdl(&self) -> String {
+        let mut printer = SdlPrinter::new(self);
+        printer.print_document();
+        printer.output
+    }
+
+    /// Renders a single object type as SDL.
+    pub fn print_object(&self, object: &Object) -> String {
+        let mut printer = SdlPrinter::new(self);
+        printer.print_object(object);
+        printer.output
+    }
+}
+
+pub(crate) struct SdlPrinter<'schema> {
+    schema: &'schema Schema,
+    pub(crate) output: String,
+}
+
+impl<'schema> SdlPrinter<'schema> {
+    pub(crate) fn new(schema: &'schema Schema) -> Self {
+        Self {
+            schema,
+            output: String::new(),
+        }
+    }
+
+    fn print_document(&mut self) {
+        self.print_schema_definition();
+
+        let mut scalars: Vec<&Scalar> = self.schema.scalars().collect();
+        scalars.sort_by_key(|scalar| self.schema.scalar_name(scalar.id));
+        for scalar in scalars {
+            if scalar.is_builtin {
+                continue;
+            }
+            self.print_scalar(scalar);
+        }
+
+        let mut objects: Vec<&Object> = self.schema.objects().collect();
+        objects.sort_by_key(|object| self.schema.object_name(object.id));
+        for object in objects {
+            self.print_object(object);
+        }
+
+        let mut interfaces: Vec<&Interface> = self.schema.interfaces().collect();
+        interfaces.sort_by_key(|interface| self.schema.interface_name(interface.id));
+        for interface in interfaces {
+            self.print_interface(interface);
+        }
+
+        let mut unions: Vec<&Union> = self.schema.unions().collect();
+        unions.sort_by_key(|union| self.schema.union_name(union.id));
+        for union in unions {
+            self.print_union(union);
+        }
+
+        let mut enums: Vec<&Enum> = self.schema.enums().collect();
+        enums.sort_by_key(|enum_| self.schema.enum_name(enum_.id));
+        for enum_ in enums {
+            self.print_enum(enum_);
+        }
+
+        let mut input_objects: Vec<&InputObject> = self.schema.input_objects().collect();
+        input_objects.sort_by_key(|input| self.schema.input_object_name(input.id));
+        for input in input_objects {
+            self.print_input_object(input);
+        }
+    }
+
+    fn print_schema_definition(&mut self) {
+        let query = self.schema.query_type();
+        let mutation = self.schema.mutation_type();
+        let subscription = self.schema.subscription_type();
+        if query.is_none() && mutation.is_none() && subscription.is_none() {
+            return;
+        }
+        self.output.push_str("schema {\n");
+        if let Some(query) = query {
+            writeln!(self.output, "  query: {}", self.schema.type_name(query)).unwrap();
+        }
+        if let Some(mutation) = mutation {
+            writeln!(self.output, "  mutation: {}", self.schema.type_name(mutation)).unwrap();
+        }
+        if let Some(subscription) = subscription {
+            writeln!(
+                self.output,
+                "  subscription: {}",
+                self.schema.type_name(subscription)
+            )
+            .unwrap();
+        }
+        self.output.push_str("}\n\n");
+    }
+
+    pub(crate) fn print_object(&mut self, object: &Object) {
+        self.print_description(object.description.as_deref(), "");
+        write!(self.output, "type {}", self.schema.object_name(object.id)).unwrap();
+        self.print_implements(&object.interfaces);
+        self.print_directives(&object.directives);
+        self.print_fields(&object.fields);
+        self.output.push('\n');
+    }
+
+    fn print_interface(&mut self, interface: &Interface) {
+        self.print_description(interface.description.as_deref(), "");
+        write!(
+            self.output,
+            "interface {}",
+            self.schema.interface_name(interface.id)
+        )
+        .unwrap();
+        self.print_implements(&interface.interfaces);
+        self.print_directives(&interface.directives);
+        self.print_fields(&interface.fields);
+        self.output.push('\n');
+    }
+
+    fn print_union(&mut self, union: &Union) {
+        self.print_description(union.description.as_deref(), "");
+        write!(self.output, "union {}", self.schema.union_name(union.id)).unwrap();
+        self.print_directives(&union.directives);
+        let members: Vec<String> = union
+            .members
+            .iter()
+            .map(|member| self.schema.object_name(*member).to_string())
+            .collect();
+        if !members.is_empty() {
+            write!(self.output, " = {}", members.join(" | ")).unwrap();
+        }
+        self.output.push_str("\n\n");
+    }
+
+    fn print_enum(&mut self, enum_: &Enum) {
+        self.print_description(enum_.description.as_deref(), "");
+        write!(self.output, "enum {}", self.schema.enum_name(enum_.id)).unwrap();
+        self.print_directives(&enum_.directives);
+        self.output.push_str(" {\n");
+        for value in &enum_.values {
+            self.print_description(value.description.as_deref(), "  ");
+            write!(self.output, "  {}", value.value).unwrap();
+            self.print_directives(&value.directives);
+            self.output.push('\n');
+        }
+        self.output.push_str("}\n\n");
+    }
+
+    fn print_input_object(&mut self, input: &InputObject) {
+        self.print_description(input.description.as_deref(), "");
+        write!(
+            self.output,
+            "input {}",
+            self.schema.input_object_name(input.id)
+        )
+        .unwrap();
+        self.print_directives(&input.directives);
+        self.output.push_str(" {\n");
+        for field in &input.fields {
+            self.print_description(field.description.as_deref(), "  ");
+            write!(
+                self.output,
+                "  {}: {}",
+                field.name,
+                self.print_type_reference(&field.type_)
+            )
+            .unwrap();
+            if let Some(default) = &field.default_value {
+                write!(self.output, " = {}", self.print_value(default)).unwrap();
+            }
+            self.print_directives(&field.directives);
+            self.output.push('\n');
+        }
+        self.output.push_str("}\n\n");
+    }
+
+    fn print_scalar(&mut self, scalar: &Scalar) {
+        self.print_description(scalar.description.as_deref(), "");
+        write!(self.output, "scalar {}", self.schema.scalar_name(scalar.id)).unwrap();
+        self.print_directives(&scalar.directives);
+        self.output.push_str("\n\n");
+    }
+
+    fn print_fields(&mut self, fields: &[crate::definitions::FieldID]) {
+        // An empty `{ }` block is invalid SDL; a fieldless type prints just its
+        // name (plus a trailing newline from the caller).
+        if fields.is_empty() {
+            self.output.push('\n');
+            return;
+        }
+        self.output.push_str(" {\n");
+        for field_id in fields {
+            let field = self.schema.field(*field_id);
+            self.print_field(field);
+        }
+        self.output.push_str("}\n");
+    }
+
+    fn print_field(&mut self, field: &Field) {
+        self.print_description(field.description.as_deref(), "  ");
+        write!(self.output, "  {}", field.name).unwrap();
+        self.print_argument_definitions(&field.arguments);
+        write!(self.output, ": {}", self.print_type_reference(&field.type_)).unwrap();
+        self.print_directives(&field.directives);
+        self.output.push('\n');
+    }
+
+    fn print_argument_definitions(&mut self, arguments: &[Argument]) {
+        if arguments.is_empty() {
+            return;
+        }
+        let rendered: Vec<String> = arguments
+            .iter()
+            .map(|argument| {
+                let mut out = format!(
+                    "{}: {}",
+                    argument.name,
+                    self.print_type_reference(&argument.type_)
+                );
+                if let Some(default) = &argument.default_value {
+                    out.push_str(&format!(" = {}", self.print_value(default)));
+                }
+                out
+            })
+            .collect();
+        write!(self.output, "({})", rendered.join(", ")).unwrap();
+    }
+
+    fn print_implements(&mut self, interfaces: &[crate::definitions::InterfaceID]) {
+        if interfaces.is_empty() {
+            return;
+        }
+        let names: Vec<String> = interfaces
+            .iter()
+            .map(|id| self.schema.interface_name(*id).to_string())
+            .collect();
+        write!(self.output, " implements {}", names.join(" & ")).unwrap();
+    }
+
+    fn print_directives(&mut self, directives: &[Directive]) {
+        for directive in directives {
+            write!(self.output, " @{}", directive.name).unwrap();
+            if !directive.arguments.is_empty() {
+                let args: Vec<String> = directive
+                    .arguments
+                    .iter()
+                    .map(|argument| {
+                        format!("{}: {}", argument.name, self.print_directive_value(&argument.value))
+                    })
+                    .collect();
+                write!(self.output, "({})", args.join(", ")).unwrap();
+            }
+        }
+    }
+
+    fn print_type_reference(&self, type_: &TypeReference) -> String {
+        match type_ {
+            TypeReference::Named(inner) => self.schema.type_name(*inner).to_string(),
+            TypeReference::NonNull(inner) => format!("{}!", self.print_type_reference(inner)),
+            TypeReference::List(inner) => format!("[{}]", self.print_type_reference(inner)),
+        }
+    }
+
+    fn print_value(&self, value: &ArgumentValue) -> String {
+        value.to_string()
+    }
+
+    fn print_directive_value(&self, value: &DirectiveValue) -> String {
+        value.to_string()
+    }
+
+    /// Descriptions are rendered as `"""` block strings (for multi-line text)
+    /// or a quoted string, indented to match the definition they decorate.
+    /// Contents are escaped so the output re-parses back to the same value.
+    fn print_description(&mut self, description: Option<&str>, indent: &str) {
+        if let Some(description) = description {
+            if description.contains('\n') {
+                // Inside a block string only the `"""` sequence needs escaping.
+                let escaped = description.replace(r#"""""#, r#"\""""#);
+                writeln!(self.output, "{indent}\"\"\"").unwrap();
+                for line in escaped.lines() {
+                    writeln!(self.output, "{indent}{line}").unwrap();
+                }
+                writeln!(self.output, "{indent}\"\"\"").unwrap();
+            } else {
+                writeln!(self.output, "{indent}\"{}\"", escape_string(description)).unwrap();
+            }
+        }
+    }
+}
+
+/// Escapes a string for a single-quoted SDL string literal.
+fn escape_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            _ => out.push(c),
+        }
+    }
+    out
+}